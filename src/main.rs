@@ -1,12 +1,13 @@
 use std::{
     env,
     fs::{self, File},
-    io::Read,
-    ops::Deref,
-    path::{Path, PathBuf},
+    io::{BufReader, Read},
+    path::Path,
     process::{self, Command},
 };
 
+use cargo_metadata::camino::Utf8PathBuf;
+
 static HELP: &str = include_str!("help.txt");
 
 /// The required post_build script call
@@ -19,96 +20,217 @@ enum BuildScriptCall {
     ///
     /// For example for `cargo build`.
     AfterCommand,
-    // TODO: Special variants for e.g. `cargo run` where the post build script needs to be
-    // run in between (i.e. after the build, but before running it).
+    /// The build script needs to be run in between building and executing the result
+    ///
+    /// For example for `cargo run`, where the script must run after the build but
+    /// before the produced binary (or test/bench harness) is launched.
+    Between,
 }
 
 fn main() {
     // check arguments
-    let mut args = env::args().peekable();
+    let mut args = env::args();
     assert!(args.next().is_some(), "no executable name in args");
     if args.next().as_deref() != Some("post") {
         panic!("cargo-post must be invoked as `cargo post`");
     }
-    if args.peek().map(Deref::deref) == Some("--help") {
+    let mut args: Vec<String> = args.collect();
+
+    // Mirror cargo's own `-C <path>` handling: change into the directory before
+    // anything else (config discovery, metadata resolution, the forwarded cargo
+    // invocation) sees a current directory.
+    if args.first().map(String::as_str) == Some("-C") {
+        let dir = args
+            .get(1)
+            .cloned()
+            .expect("no directory specified after -C");
+        env::set_current_dir(&dir)
+            .unwrap_or_else(|err| panic!("failed to change directory to `{}`: {:?}", dir, err));
+        args.drain(0..2);
+    }
+
+    if args.first().map(String::as_str) == Some("--help") {
         println!("{}", HELP);
         return;
     }
-    if args.peek().map(Deref::deref) == Some("--version") {
+    if args.first().map(String::as_str) == Some("--version") {
         println!("cargo-post {}", env!("CARGO_PKG_VERSION"));
         return;
     }
 
-    let build_script_call = match args.peek().map(Deref::deref) {
-        Some(cmd) => match cmd {
-            "b" | "build" | "xbuild" => BuildScriptCall::AfterCommand,
-            "c" | "check" | "clean" | "doc" | "new" | "init" | "update" | "search"
-            | "uninstall" => BuildScriptCall::NoCall,
-            cmd if ["run", "test", "bench", "publish", "install"].contains(&cmd) => {
-                panic!("`cargo post {}` is not supported yet", cmd)
-            }
-            cmd => panic!("unknown cargo command `cargo {}`", cmd),
-        },
-        None => BuildScriptCall::NoCall,
-    };
-
-    // run cargo
-    let mut cmd = Command::new("cargo");
-    cmd.args(args);
-    let exit_status = match cmd.status() {
-        Ok(status) => status,
-        Err(err) => panic!("failed to execute command `{:?}`: {:?}", cmd, err),
-    };
-    if !exit_status.success() {
-        process::exit(exit_status.code().unwrap_or(1));
-    }
+    let build_script_call = resolve_build_script_call(&mut args);
 
     match build_script_call {
-        BuildScriptCall::NoCall => {}
+        BuildScriptCall::NoCall => {
+            let mut cmd = Command::new("cargo");
+            cmd.args(&args);
+            let exit_status = cmd
+                .status()
+                .unwrap_or_else(|err| panic!("failed to execute command `{:?}`: {:?}", cmd, err));
+            if !exit_status.success() {
+                process::exit(exit_status.code().unwrap_or(1));
+            }
+        }
         BuildScriptCall::AfterCommand => {
-            if let Some(exit_status) = run_post_build_script() {
+            let package = resolve_target_package(&args);
+            let artifacts = run_cargo_build(&args, &package);
+            if let Some(exit_status) = run_post_build_script(&args, &package, &artifacts) {
                 if !exit_status.success() {
                     process::exit(exit_status.code().unwrap_or(1));
                 }
             }
         }
+        BuildScriptCall::Between => run_between_build_and_execute(args),
+    }
+}
+
+/// Classifies a literal (i.e. already expanded) cargo subcommand, or returns `None`
+/// if it isn't one of cargo-post's known built-in commands.
+fn classify_command(cmd: &str) -> Option<BuildScriptCall> {
+    Some(match cmd {
+        "b" | "build" | "xbuild" => BuildScriptCall::AfterCommand,
+        "c" | "check" | "clean" | "doc" | "new" | "init" | "update" | "search" | "uninstall" => {
+            BuildScriptCall::NoCall
+        }
+        "run" | "test" | "bench" => BuildScriptCall::Between,
+        cmd if ["publish", "install"].contains(&cmd) => {
+            panic!("`cargo post {}` is not supported yet", cmd)
+        }
+        _ => return None,
+    })
+}
+
+/// Determines the `BuildScriptCall` for `args`, expanding the leading command through
+/// `[alias]` entries in `.cargo/config` first if it isn't a command cargo-post knows
+/// about directly, the same way cargo itself expands aliases before dispatching.
+fn resolve_build_script_call(args: &mut Vec<String>) -> BuildScriptCall {
+    let Some(cmd) = args.first().cloned() else {
+        return BuildScriptCall::NoCall;
     };
+    if let Some(call) = classify_command(&cmd) {
+        return call;
+    }
+
+    let current_dir = env::current_dir().expect("failed to get current directory");
+    match find_cargo_config_alias(&current_dir, &cmd) {
+        Some(expansion) => {
+            args.splice(0..1, expansion);
+            args.first()
+                .and_then(|cmd| classify_command(cmd))
+                .unwrap_or(BuildScriptCall::NoCall)
+        }
+        None => panic!("unknown cargo command `cargo {}`", cmd),
+    }
 }
 
-fn run_post_build_script() -> Option<process::ExitStatus> {
-    let rustc_metadata =
-        rustc_version::version_meta().expect("cannot query rustc version metadata");
+/// Runs `cargo run`/`test`/`bench` with the post build script sandwiched in between the
+/// build and the execution of the produced binary (or test/bench harnesses).
+fn run_between_build_and_execute(args: Vec<String>) {
+    let subcommand = args[0].clone();
+    let rest = &args[1..];
+
+    // a trailing `-- args` section is for the executed binary (or harnesses); split
+    // it off so it doesn't get passed to the build phase
+    let cargo_args_end = rest.iter().position(|arg| arg == "--").unwrap_or(rest.len());
+    let cargo_args = &rest[..cargo_args_end];
+    let program_args = &rest[(cargo_args_end + 1).min(rest.len())..];
+
+    // Phase 1: build (without running/testing/benchmarking anything yet)
+    let build_args: Vec<String> = match subcommand.as_str() {
+        "run" => std::iter::once("build".to_owned())
+            .chain(cargo_args.iter().cloned())
+            .collect(),
+        "test" | "bench" => std::iter::once(subcommand.clone())
+            .chain(cargo_args.iter().cloned())
+            .chain(std::iter::once("--no-run".to_owned()))
+            .collect(),
+        _ => unreachable!("`{}` does not require a between-call", subcommand),
+    };
+    let package = resolve_target_package(&build_args);
+    let artifacts = run_cargo_build(&build_args, &package);
 
+    // Phase 2: run the post build script
+    if let Some(exit_status) = run_post_build_script(&build_args, &package, &artifacts) {
+        if !exit_status.success() {
+            process::exit(exit_status.code().unwrap_or(1));
+        }
+    }
+
+    // Phase 3: execute the binary (or harness) that was produced in phase 1
+    match subcommand.as_str() {
+        "run" => {
+            let binary_path = select_executable(&build_args, &artifacts)
+                .unwrap_or_else(|candidates| {
+                    panic!(
+                        "`cargo post run` could not determine which binary to run, \
+                         found {}; use `--bin` to disambiguate",
+                        candidates.join(", ")
+                    )
+                })
+                .expect("cargo did not report a runnable binary artifact")
+                .into_std_path_buf();
+            let mut cmd = Command::new(&binary_path);
+            cmd.args(program_args);
+            let exit_status = cmd.status().unwrap_or_else(|err| {
+                panic!(
+                    "failed to execute binary `{}`: {:?}",
+                    binary_path.display(),
+                    err
+                )
+            });
+            if !exit_status.success() {
+                process::exit(exit_status.code().unwrap_or(1));
+            }
+        }
+        "test" | "bench" => {
+            // The harnesses were already built in phase 1, so cargo sees that
+            // nothing changed and goes straight to running them.
+            let mut cmd = Command::new("cargo");
+            cmd.arg(&subcommand).args(cargo_args);
+            if !program_args.is_empty() {
+                cmd.arg("--").args(program_args);
+            }
+            let exit_status = cmd
+                .status()
+                .unwrap_or_else(|err| panic!("failed to execute command `{:?}`: {:?}", cmd, err));
+            if !exit_status.success() {
+                process::exit(exit_status.code().unwrap_or(1));
+            }
+        }
+        _ => unreachable!("`{}` does not require a between-call", subcommand),
+    }
+}
+
+/// The package the current `cargo post` invocation is building, resolved once up
+/// front so the same `cargo_metadata` query backs both the build (artifact
+/// filtering) and the post build script (manifest lookup) instead of running twice.
+struct ResolvedPackage {
+    metadata: cargo_metadata::Metadata,
+    package: cargo_metadata::Package,
+}
+
+/// Resolves the `--manifest-path`/`--package`/`-p` arguments in `build_args` to the
+/// single package being built, the same way cargo itself would.
+fn resolve_target_package(build_args: &[String]) -> ResolvedPackage {
     let mut cmd = cargo_metadata::MetadataCommand::new();
     cmd.no_deps();
-    let manifest_path = {
-        let mut args = env::args().skip_while(|val| !val.starts_with("--manifest-path"));
-        match args.next() {
-            Some(ref p) if p == "--manifest-path" => Some(args.next().unwrap()),
-            Some(p) => Some(p.trim_start_matches("--manifest-path=").to_owned()),
-            None => None,
-        }
-    };
+    let manifest_path = find_flag_value(build_args, "--manifest-path");
     if let Some(ref manifest_path) = manifest_path {
         cmd.manifest_path(manifest_path);
     }
-    let metadata = cmd.exec().unwrap();
+    let metadata = cmd.exec().expect("failed to run `cargo metadata`");
 
+    let package_name = find_flag_value(build_args, "--package")
+        .or_else(|| find_flag_value(build_args, "-p"));
     let package = {
-        let mut args =
-            env::args().skip_while(|val| !val.starts_with("--package") && !val.starts_with("-p"));
-        let package_name = match args.next() {
-            Some(ref p) if p == "--package" || p == "-p" => Some(args.next().unwrap()),
-            Some(p) => Some(p.trim_start_matches("--package=").to_owned()),
-            None => None,
-        };
         let mut packages = metadata.packages.iter();
         match package_name {
             Some(name) => packages
                 .find(|p| p.name == name)
-                .expect("specified package not found"),
+                .expect("specified package not found")
+                .clone(),
             None => {
-                let package = packages.next().expect("workspace has no packages");
+                let package = packages.next().expect("workspace has no packages").clone();
                 assert!(
                     packages.next().is_none(),
                     "Please specify a `--package` argument"
@@ -117,10 +239,137 @@ fn run_post_build_script() -> Option<process::ExitStatus> {
             }
         }
     };
+    ResolvedPackage { metadata, package }
+}
+
+/// Looks up `flag`'s value in `args`, accepting both `--flag value` and
+/// `--flag=value` forms (the two forms cargo itself accepts for most flags).
+fn find_flag_value(args: &[String], flag: &str) -> Option<String> {
+    let prefix = format!("{}=", flag);
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == flag {
+            return args.next().cloned();
+        }
+        if let Some(value) = arg.strip_prefix(&prefix) {
+            return Some(value.to_owned());
+        }
+    }
+    None
+}
+
+/// The compiler artifacts produced by a build, discovered from cargo's
+/// `--message-format=json-render-diagnostics` output instead of guessed from
+/// `target_directory`/target-triple/profile path heuristics. Only artifacts
+/// belonging to the package being built are kept; a dependency's (or a build
+/// script's own) files never leak into `all` or `executables`.
+#[derive(Default)]
+struct BuildArtifacts {
+    /// Every non-build-script file produced for the target package.
+    all: Vec<Utf8PathBuf>,
+    /// `(target name, executable path)` for every runnable bin/example the target
+    /// package produced.
+    executables: Vec<(String, Utf8PathBuf)>,
+}
+
+impl BuildArtifacts {
+    /// The directory the produced files were written to, derived from wherever the
+    /// build actually placed them rather than hand-joining `target_directory`/
+    /// triple/profile (which breaks for custom profiles, renamed target dirs, and
+    /// dependency artifacts landing in a `deps` subdirectory first).
+    fn out_dir(&self) -> Option<Utf8PathBuf> {
+        self.executables
+            .first()
+            .map(|(_, path)| path)
+            .or_else(|| self.all.first())
+            .and_then(|path| path.parent())
+            .map(|path| path.to_path_buf())
+    }
+}
 
-    let manifest_path = manifest_path
-        .map(PathBuf::from)
-        .unwrap_or_else(|| package.manifest_path.clone().into());
+/// Selects the executable to run, honoring an explicit `--bin`/`--example` in
+/// `build_args`. With no explicit selection, returns the executable if the build
+/// produced exactly one, `None` if it produced none, and the ambiguous candidate
+/// names as an error if it produced several.
+fn select_executable(
+    build_args: &[String],
+    artifacts: &BuildArtifacts,
+) -> Result<Option<Utf8PathBuf>, Vec<String>> {
+    let explicit_name = find_flag_value(build_args, "--bin").or_else(|| find_flag_value(build_args, "--example"));
+    if let Some(name) = explicit_name {
+        return Ok(artifacts
+            .executables
+            .iter()
+            .find(|(target_name, _)| *target_name == name)
+            .map(|(_, path)| path.clone()));
+    }
+    match artifacts.executables.as_slice() {
+        [] => Ok(None),
+        [(_, path)] => Ok(Some(path.clone())),
+        many => Err(many.iter().map(|(name, _)| name.clone()).collect()),
+    }
+}
+
+/// Runs `cargo <build_args>`, the one and only build cargo-post performs for this
+/// invocation, piping its `--message-format=json-render-diagnostics` output to
+/// discover which files it produced for `package` instead of re-running the build a
+/// second time just to find out. Diagnostics (warnings/errors) are re-printed as
+/// they arrive so the JSON capture stays invisible to the user.
+fn run_cargo_build(build_args: &[String], package: &ResolvedPackage) -> BuildArtifacts {
+    let mut cmd = Command::new("cargo");
+    cmd.args(build_args);
+    cmd.arg("--message-format=json-render-diagnostics");
+    cmd.stdout(process::Stdio::piped());
+    let mut child = cmd
+        .spawn()
+        .unwrap_or_else(|err| panic!("failed to execute command `{:?}`: {:?}", cmd, err));
+    let stdout = child.stdout.take().expect("no stdout captured from cargo");
+
+    let mut artifacts = BuildArtifacts::default();
+    for message in cargo_metadata::Message::parse_stream(BufReader::new(stdout)) {
+        match message.expect("failed to parse cargo's JSON build output") {
+            cargo_metadata::Message::CompilerMessage(msg) => {
+                if let Some(rendered) = &msg.message.rendered {
+                    eprint!("{}", rendered);
+                }
+            }
+            cargo_metadata::Message::CompilerArtifact(artifact) => {
+                if artifact.package_id != package.package.id {
+                    continue;
+                }
+                // A custom build script's own helper binary is reported under the
+                // package it belongs to too; it's not part of the package's output.
+                if artifact.target.kind.iter().any(|kind| kind == "custom-build") {
+                    continue;
+                }
+                if let Some(executable) = &artifact.executable {
+                    artifacts
+                        .executables
+                        .push((artifact.target.name.clone(), executable.clone()));
+                }
+                artifacts.all.extend(artifact.filenames);
+            }
+            _ => {}
+        }
+    }
+
+    let exit_status = child.wait().expect("failed to wait for cargo build");
+    if !exit_status.success() {
+        process::exit(exit_status.code().unwrap_or(1));
+    }
+    artifacts
+}
+
+fn run_post_build_script(
+    build_args: &[String],
+    resolved_package: &ResolvedPackage,
+    artifacts: &BuildArtifacts,
+) -> Option<process::ExitStatus> {
+    let rustc_metadata =
+        rustc_version::version_meta().expect("cannot query rustc version metadata");
+
+    let ResolvedPackage { metadata, package } = resolved_package;
+    let manifest_path = package.manifest_path.clone().into_std_path_buf();
     let manifest_dir = manifest_path.parent().expect("failed to get crate folder");
     let post_build_script_path = manifest_dir.join("post_build.rs");
 
@@ -148,9 +397,15 @@ fn run_post_build_script() -> Option<process::ExitStatus> {
         .and_then(|table| table.get("metadata"))
         .and_then(|table| table.get("cargo-post"));
 
-    let dependencies = cargo_post_metadata
+    // `package.metadata.cargo-post.dependencies` in Cargo.toml is the baseline, but
+    // post_build.rs may also declare dependencies itself via an embedded-manifest
+    // style frontmatter; the script's own frontmatter wins on conflicting keys.
+    let frontmatter_dependencies = parse_frontmatter_dependencies(&post_build_script_path);
+    let metadata_dependencies = cargo_post_metadata
         .and_then(|table| table.get("dependencies"))
         .cloned();
+    let dependencies =
+        merge_dependency_tables(metadata_dependencies, frontmatter_dependencies).map(toml::Value::Table);
     let dependencies_string = if let Some(mut dependencies) = dependencies {
         // adjust path dependencies
         for (dep_name, dependency) in dependencies
@@ -209,14 +464,9 @@ fn run_post_build_script() -> Option<process::ExitStatus> {
         // - target CLI flag
         // - $CARGO_BUILD_TARGET
         // - build.target in a .cargo/config file
-        let mut args = env::args().skip_while(|val| !val.starts_with("--target"));
-        match args.next() {
-            Some(ref p) if p == "--target" => Some(args.next().expect("no target after --target")),
-            Some(p) => Some(p.trim_start_matches("--target=").to_owned()),
-            None => env::var("CARGO_BUILD_TARGET")
-                .ok()
-                .or(find_cargo_config_target(manifest_dir)),
-        }
+        find_flag_value(build_args, "--target")
+            .or_else(|| env::var("CARGO_BUILD_TARGET").ok())
+            .or_else(|| find_cargo_config_target(manifest_dir))
     };
     let target_triple = {
         let file_stem = target_path.as_ref().map(|t| {
@@ -227,22 +477,31 @@ fn run_post_build_script() -> Option<process::ExitStatus> {
         });
         file_stem.map(|s| s.into_string().expect("target not a valid string"))
     };
-    let profile = if env::args().any(|arg| arg == "--release" || arg == "-r") {
-        "release"
-    } else {
-        "debug"
-    };
-    let mut out_dir = metadata.target_directory.clone();
-    if let Some(ref target_triple) = target_triple {
-        out_dir.push(target_triple);
-    }
-    out_dir.push(profile);
-    let build_command = {
-        let mut cmd = String::from("cargo ");
-        let args: Vec<String> = env::args().skip(2).collect();
-        cmd.push_str(&args.join(" "));
-        cmd
+    let profile = find_flag_value(build_args, "--profile").unwrap_or_else(|| {
+        if build_args.iter().any(|arg| arg == "--release" || arg == "-r") {
+            "release".to_owned()
+        } else {
+            "debug".to_owned()
+        }
+    });
+    // cargo maps the `dev` profile to a `debug` directory and keeps every other
+    // profile name (including `release`) as its own directory name.
+    let profile_dir = match profile.as_str() {
+        "dev" => "debug",
+        other => other,
     };
+    // The build already ran once (in `run_cargo_build`); reuse the artifacts it
+    // captured instead of hand-joining `target_directory`/triple/profile (which
+    // breaks for custom profiles, renamed target dirs, and multi-binary crates).
+    let out_dir = artifacts.out_dir().unwrap_or_else(|| {
+        let mut out_dir = metadata.target_directory.clone();
+        if let Some(ref target_triple) = target_triple {
+            out_dir.push(target_triple);
+        }
+        out_dir.push(profile_dir);
+        out_dir
+    });
+    let build_command = format!("cargo {}", build_args.join(" "));
 
     let is_target_mismatch = target_triple
         .as_ref()
@@ -282,6 +541,22 @@ fn run_post_build_script() -> Option<process::ExitStatus> {
     );
     cmd.env("CRATE_TARGET_DIR", metadata.target_directory.as_os_str());
     cmd.env("CRATE_OUT_DIR", out_dir);
+    cmd.env(
+        "CRATE_BINARY_PATH",
+        select_executable(build_args, artifacts)
+            .ok()
+            .flatten()
+            .unwrap_or_default(),
+    );
+    cmd.env(
+        "CRATE_ARTIFACTS",
+        artifacts
+            .all
+            .iter()
+            .map(|path| path.as_str())
+            .collect::<Vec<_>>()
+            .join("\n"),
+    );
     cmd.env("CRATE_TARGET", target_path.unwrap_or_default());
     cmd.env("CRATE_TARGET_TRIPLE", target_triple.unwrap_or_default());
     cmd.env("CRATE_PROFILE", profile);
@@ -289,10 +564,74 @@ fn run_post_build_script() -> Option<process::ExitStatus> {
     Some(cmd.status().expect("Failed to run post build script"))
 }
 
-fn find_cargo_config_target(path: &Path) -> Option<String> {
-    // Cargo config path resolution works in accordance with:
-    // https://doc.rust-lang.org/cargo/reference/config.html#hierarchical-structure
+/// Parses the `[dependencies]` table out of a post_build.rs frontmatter, i.e. a
+/// leading `//!` doc comment containing a ```` ```cargo ```` fenced code block —
+/// the legacy `cargo-script` embedded-manifest format, not the `---`-delimited
+/// frontmatter stabilized later for single-file packages. post_build.rs is spliced
+/// into a synthetic crate and compiled as-is (see `build_script_manifest_content`
+/// above), so a leading `---` block would be left in the file rustc compiles;
+/// rustc itself recognizes (and currently rejects as unstable) that syntax, which
+/// would turn a dependency declaration into a hard compile error. The `//!` form
+/// is just an ordinary doc comment, so it round-trips through rustc unchanged.
+fn parse_frontmatter_dependencies(post_build_script_path: &Path) -> Option<toml::Value> {
+    let content =
+        fs::read_to_string(post_build_script_path).expect("Failed to read post_build.rs");
+
+    let mut toml_lines = Vec::new();
+    let mut in_block = false;
+    for line in content.lines() {
+        let Some(doc) = line.trim_start().strip_prefix("//!") else {
+            break;
+        };
+        let doc = doc.strip_prefix(' ').unwrap_or(doc);
+        if !in_block {
+            if doc.trim() == "```cargo" {
+                in_block = true;
+            }
+            continue;
+        }
+        if doc.trim() == "```" {
+            break;
+        }
+        toml_lines.push(doc);
+    }
+
+    if !in_block {
+        return None;
+    }
+    let frontmatter: toml::Table = toml_lines
+        .join("\n")
+        .parse()
+        .expect("invalid TOML in post_build.rs frontmatter");
+    frontmatter.get("dependencies").cloned()
+}
+
+/// Merges two optional `[dependencies]` tables, with entries from `overrides`
+/// taking precedence over same-named entries in `base`.
+fn merge_dependency_tables(
+    base: Option<toml::Value>,
+    overrides: Option<toml::Value>,
+) -> Option<toml::value::Table> {
+    fn into_table(dependencies: toml::Value) -> toml::value::Table {
+        match dependencies {
+            toml::Value::Table(table) => table,
+            _ => panic!("`[dependencies]` must be a table"),
+        }
+    }
+
+    let mut merged = base.map(into_table).unwrap_or_default();
+    merged.extend(overrides.map(into_table).unwrap_or_default());
+    if merged.is_empty() {
+        None
+    } else {
+        Some(merged)
+    }
+}
 
+/// Walks the hierarchical cargo config locations starting at `path`, in accordance
+/// with <https://doc.rust-lang.org/cargo/reference/config.html#hierarchical-structure>,
+/// returning the first non-`None` result that `parse` produces from a config file.
+fn find_in_cargo_config<T>(path: &Path, parse: &impl Fn(&Path) -> Option<T>) -> Option<T> {
     // Set up a path for $CARGO_HOME
     let cargo_home = env::var("CARGO_HOME").unwrap();
     let cargo_home = Path::new(&cargo_home);
@@ -306,9 +645,9 @@ fn find_cargo_config_target(path: &Path) -> Option<String> {
     // First attempt to find and parse variants for current given path
     for config_path in paths {
         if config_path.exists() {
-            let target = parse_build_target(&config_path);
-            if target.is_some() {
-                return target;
+            let value = parse(&config_path);
+            if value.is_some() {
+                return value;
             }
         }
     }
@@ -320,18 +659,22 @@ fn find_cargo_config_target(path: &Path) -> Option<String> {
 
     if let Some(p) = path.parent() {
         // Our current path still has a parent, recurse into it
-        find_cargo_config_target(p)
+        find_in_cargo_config(p, parse)
     } else {
         if path.ne(cargo_home) {
             // Our current path is effectively at the root of the volume;
             // attempt to find configuration at $CARGO_HOME/config.toml
-            return find_cargo_config_target(cargo_home);
+            return find_in_cargo_config(cargo_home, parse);
         }
         // All stop conditions have been met and no target has been found
         None
     }
 }
 
+fn find_cargo_config_target(path: &Path) -> Option<String> {
+    find_in_cargo_config(path, &parse_build_target)
+}
+
 fn parse_build_target(path: &Path) -> Option<String> {
     let content = fs::read_to_string(path).expect("cannot read cargo config file");
     let parsed: toml::Table = content.parse().expect("cannot parse cargo config toml");
@@ -347,3 +690,27 @@ fn parse_build_target(path: &Path) -> Option<String> {
     }
     None
 }
+
+/// Looks up `name` in the `[alias]` table of `.cargo/config`, expanded to its list
+/// of words (cargo accepts both a single string and a list of strings).
+fn find_cargo_config_alias(path: &Path, name: &str) -> Option<Vec<String>> {
+    find_in_cargo_config(path, &|config_path| parse_alias(config_path, name))
+}
+
+fn parse_alias(path: &Path, name: &str) -> Option<Vec<String>> {
+    let content = fs::read_to_string(path).expect("cannot read cargo config file");
+    let parsed: toml::Table = content.parse().expect("cannot parse cargo config toml");
+    let alias = parsed.get("alias")?.get(name)?;
+    Some(match alias {
+        toml::Value::String(s) => s.split_whitespace().map(str::to_owned).collect(),
+        toml::Value::Array(words) => words
+            .iter()
+            .map(|word| {
+                word.as_str()
+                    .expect("alias list entries must be strings")
+                    .to_owned()
+            })
+            .collect(),
+        _ => panic!("alias `{}` must be a string or a list of strings", name),
+    })
+}
@@ -0,0 +1,33 @@
+use std::{env, path::PathBuf};
+
+fn main() {
+    let current_dir = env::current_dir().unwrap();
+
+    // Built with `--bin alpha`, so only `alpha` is compiled; `beta` never runs,
+    // and CRATE_BINARY_PATH/CRATE_ARTIFACTS must reflect that, not just the
+    // first executable cargo happened to report.
+    assert_eq!(
+        env::var("CRATE_BUILD_COMMAND").unwrap(),
+        "cargo build --package artifacts --bin alpha"
+    );
+
+    let binary_path = PathBuf::from(env::var("CRATE_BINARY_PATH").unwrap());
+    assert_eq!(binary_path.file_name().unwrap(), "alpha");
+    assert_eq!(
+        binary_path.parent().unwrap(),
+        PathBuf::from(env::var("CRATE_OUT_DIR").unwrap())
+    );
+
+    let artifacts: Vec<_> = env::var("CRATE_ARTIFACTS")
+        .unwrap()
+        .lines()
+        .map(PathBuf::from)
+        .collect();
+    assert_eq!(artifacts, vec![binary_path]);
+
+    assert_eq!(
+        PathBuf::from(env::var("CRATE_MANIFEST_DIR").unwrap()),
+        current_dir
+    );
+    println!("ok");
+}
@@ -0,0 +1,32 @@
+use std::{env, path::PathBuf};
+
+fn main() {
+    let current_dir = env::current_dir().unwrap();
+    let current_parent = current_dir.parent().unwrap();
+    assert_eq!(
+        env::var("CRATE_BUILD_COMMAND").unwrap(),
+        "cargo build --package custom_profile --profile release-lto"
+    );
+    assert_eq!(
+        PathBuf::from(env::var("CRATE_MANIFEST_DIR").unwrap()),
+        current_dir
+    );
+    assert_eq!(
+        PathBuf::from(env::var("CRATE_MANIFEST_PATH").unwrap()),
+        current_dir.join("Cargo.toml")
+    );
+    // `release-lto` is a made-up profile name, so unlike `dev`/`release` it keeps
+    // its own name as the target subdirectory instead of mapping to debug/release.
+    assert_eq!(env::var("CRATE_PROFILE").unwrap(), "release-lto");
+    assert_eq!(env::var("CRATE_TARGET").unwrap(), "");
+    assert_eq!(env::var("CRATE_TARGET_TRIPLE").unwrap(), "");
+    assert_eq!(
+        PathBuf::from(env::var("CRATE_TARGET_DIR").unwrap()),
+        current_parent.join("target")
+    );
+    assert_eq!(
+        PathBuf::from(env::var("CRATE_OUT_DIR").unwrap()),
+        current_parent.join("target").join("release-lto")
+    );
+    println!("ok");
+}